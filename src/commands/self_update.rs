@@ -7,11 +7,17 @@ use std::io::Write;
 use std::path::Component;
 use std::path::Path;
 use std::path::PathBuf;
+use std::process::Command;
 use std::process::ExitCode;
 
 use clap::Parser;
+use clap::ValueEnum;
+use ed25519_dalek::Signature;
+use ed25519_dalek::VerifyingKey;
+use ed25519_dalek::Verifier;
 use self_update::Download;
 use self_update::Extract;
+use self_update::backends::github::ReleaseList;
 use self_update::backends::github::Update;
 use self_update::errors::Error as SelfUpdateError;
 use self_update::self_replace;
@@ -21,6 +27,11 @@ use self_update::update::ReleaseUpdate;
 use self_update::update::UpdateStatus;
 use self_update::version::bump_is_compatible;
 use self_update::version::bump_is_greater;
+use semver::Version;
+use serde::Deserialize;
+use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 use tempfile::TempDir;
 use tracing::debug;
 use tracing::info;
@@ -29,6 +40,47 @@ use tracing::warn;
 use crate::consts::*;
 use crate::error::Error;
 
+/// Ed25519 public key, as lowercase hex, that release archives are signed with.
+///
+/// A detached `<asset>.sig` file is treated as a signature over the raw archive
+/// bytes and checked against this key before extraction. When a key is embedded,
+/// the `.sig` manifest is mandatory — omitting it is rejected rather than
+/// silently skipped, so an attacker cannot bypass authenticity by dropping the
+/// signature asset. Leave empty to disable signature verification for releases
+/// that predate signing; the mandatory `.sha256` checksum is still enforced
+/// regardless.
+const RELEASE_SIGNING_KEY: &str = "";
+
+/// Release channel Mago tracks when selecting an update.
+///
+/// Channels partition the published tags by their semver pre-release identifier
+/// so that, for example, a user on `stable` is only ever offered final releases.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Channel {
+    /// Final releases only (tags with an empty semver pre-release, e.g. `1.0.0`).
+    #[default]
+    Stable,
+    /// Pre-releases carrying a `beta` or `rc` identifier (e.g. `1.0.0-beta.10`).
+    Beta,
+    /// The bleeding edge: the highest version regardless of pre-release status.
+    Edge,
+}
+
+impl Channel {
+    /// Whether `version` belongs to this channel.
+    fn accepts(&self, version: &Version) -> bool {
+        match self {
+            Channel::Stable => version.pre.is_empty(),
+            Channel::Beta => {
+                let pre = version.pre.as_str();
+                pre.starts_with("beta") || pre.starts_with("rc")
+            }
+            Channel::Edge => true,
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[command(
     name = "self-update",
@@ -61,9 +113,89 @@ pub struct SelfUpdateCommand {
     /// If the specified version is not found, an error will be returned.
     #[arg(long, value_name = "VERSION_TAG")]
     pub tag: Option<String>,
+
+    /// Skip checksum and signature verification of the downloaded archive.
+    ///
+    /// By default the downloaded archive is verified against a companion `.sha256`
+    /// checksum (and, when a signing key is embedded, a `.sig` signature) published
+    /// in the same release.
+    /// Use this flag only for older releases that were published before signing
+    /// was introduced and therefore ship without a checksum manifest.
+    #[arg(long)]
+    pub insecure_skip_verify: bool,
+
+    /// Track a specific release channel when selecting an update.
+    ///
+    /// `stable` follows final releases, `beta` follows `beta`/`rc` pre-releases, and
+    /// `edge` always picks the highest published version. The chosen channel is
+    /// persisted by any run other than `--check`, so later bare `self-update` runs
+    /// keep tracking it. When `--tag` pins an exact version, the channel plays no
+    /// part in selecting it.
+    #[arg(long, value_enum)]
+    pub channel: Option<Channel>,
+
+    /// Restore the previously installed version from its backup.
+    ///
+    /// After a successful update the replaced executable is kept as a versioned
+    /// backup next to the installed binary. This flag restores that backup, which
+    /// is useful when a new version introduces a regression, without re-downloading
+    /// anything.
+    #[arg(long)]
+    pub rollback: bool,
+
+    /// Delegate the upgrade to Homebrew (`brew upgrade mago`).
+    ///
+    /// Homebrew installations are detected automatically, but this flag forces the
+    /// Homebrew path even when detection is inconclusive.
+    #[arg(long)]
+    pub homebrew: bool,
 }
 
 pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
+    if command.rollback {
+        return perform_rollback();
+    }
+
+    // Homebrew installs upgrade through `brew`, not by replacing the binary in
+    // place. Resolve this before any GitHub lookup so `--homebrew` can force the
+    // path even when the network is unavailable, and so a bare `self-update` on a
+    // Homebrew install makes no spurious release API calls. `--check` stays
+    // read-only, so it falls through to the reporting path below rather than
+    // triggering `brew upgrade`.
+    if !command.check && (command.homebrew || is_homebrew_install()) {
+        return run_homebrew_upgrade(command.no_confirm);
+    }
+
+    // An explicit `--tag` wins over any channel; otherwise resolve the channel to
+    // track (from the flag, falling back to the persisted preference) and pin the
+    // best matching tag before handing off to the backend. The requested channel
+    // is persisted by any run other than a read-only `--check`, including one that
+    // finds nothing newer on it.
+    let requested_channel = command.channel;
+    let resolved_tag = match &command.tag {
+        Some(tag) => Some(tag.clone()),
+        None => {
+            let channel = requested_channel.unwrap_or_else(load_channel);
+            debug!("Tracking release channel: {:?}", channel);
+            match select_channel_tag(channel)? {
+                // Pin the highest in-channel tag so the backend targets it exactly.
+                // A `None` here means nothing newer exists on the channel: on
+                // `stable` the highest final release may be older than the beta the
+                // user is currently running, and that must never be a downgrade.
+                Some(tag) => Some(tag),
+                None => {
+                    if !command.check {
+                        if let Some(channel) = requested_channel {
+                            save_channel(channel)?;
+                        }
+                    }
+                    info!("Already up-to-date with the latest version `{}`", VERSION);
+                    return Ok(ExitCode::SUCCESS);
+                }
+            }
+        }
+    };
+
     let mut status_builder = Update::configure();
     status_builder
         .repo_owner(REPO_OWNER)
@@ -74,8 +206,8 @@ pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
         .bin_path_in_archive("{{ bin }}-{{ version }}-{{ target }}/{{ bin }}")
         .no_confirm(command.no_confirm);
 
-    if let Some(tag) = command.tag {
-        status_builder.target_version_tag(&tag);
+    if let Some(tag) = &resolved_tag {
+        status_builder.target_version_tag(tag);
     }
 
     let release_update = status_builder.build()?;
@@ -121,12 +253,13 @@ pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
         });
     }
 
-    if is_homebrew_install() {
-        warn!("Homebrew installations can't self-update; run `brew upgrade mago` instead");
-        return Ok(ExitCode::FAILURE);
+    // Persist the channel before starting the update, so that later bare
+    // `self-update` runs keep tracking it.
+    if let Some(channel) = requested_channel {
+        save_channel(channel)?;
     }
 
-    let status = perform_update(release_update)?;
+    let status = perform_update(release_update, command.insecure_skip_verify)?;
 
     match status {
         UpdateStatus::UpToDate => {
@@ -140,7 +273,177 @@ pub fn execute(command: SelfUpdateCommand) -> Result<ExitCode, Error> {
     Ok(ExitCode::SUCCESS)
 }
 
-fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus, Error> {
+/// Default interval between passive update checks when the cache is consulted.
+const DEFAULT_UPDATE_CHECK_INTERVAL_SECS: u64 = 24 * 60 * 60;
+
+/// A newer release discovered by the passive update check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvailableUpdate {
+    /// The latest available version string (e.g. `1.0.0`).
+    pub version: String,
+}
+
+impl AvailableUpdate {
+    /// The text of the single-line banner; [`print_update_notification`] dims it.
+    pub fn notification_line(&self) -> String {
+        format!("A new version of Mago ({}) is available — run 'mago self-update'", self.version)
+    }
+}
+
+/// Print the passive "update available" banner to stderr.
+///
+/// Nothing in this module calls it, and it defines no command-line flag of its
+/// own; the caller passes `no_update_check` to suppress the banner. The banner is
+/// also suppressed (via [`check_for_update_passive`]) under CI or when
+/// `MAGO_NO_UPDATE_CHECK` is present. A failed check prints nothing and never
+/// fails the caller, though a stale cache makes the check wait on the network.
+pub fn print_update_notification(no_update_check: bool) {
+    if no_update_check {
+        return;
+    }
+
+    if let Some(update) = check_for_update_passive() {
+        // A single dimmed line on stderr so it never pollutes piped stdout.
+        eprintln!("\x1b[2m{}\x1b[0m", update.notification_line());
+    }
+}
+
+/// On-disk record of the last passive update check.
+#[derive(Debug, Serialize, Deserialize)]
+struct UpdateCheckCache {
+    /// Unix timestamp (seconds) of the last check.
+    last_checked: u64,
+    /// The channel the latest version was fetched for.
+    #[serde(default)]
+    channel: Channel,
+    /// The latest version seen at that time.
+    latest_version: String,
+}
+
+/// Throttled check for a newer release, suitable for calling from other
+/// subcommands on every invocation.
+///
+/// If the cache is younger than the configured interval (default 24h, overridable
+/// via `MAGO_UPDATE_CHECK_INTERVAL`), the cached result is returned without any
+/// network access. Otherwise the latest release on the tracked channel is fetched
+/// (see [`latest_version_for_channel`]), the cache
+/// is rewritten, and the result returned. The check is suppressed entirely when
+/// running under CI or when `MAGO_NO_UPDATE_CHECK` is set, and every failure path
+/// degrades to `None` so a flaky network never blocks the real command.
+pub fn check_for_update_passive() -> Option<AvailableUpdate> {
+    if env::var_os("CI").is_some() || env::var_os("MAGO_NO_UPDATE_CHECK").is_some() {
+        return None;
+    }
+
+    let now = unix_timestamp()?;
+    let interval = env::var("MAGO_UPDATE_CHECK_INTERVAL")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(DEFAULT_UPDATE_CHECK_INTERVAL_SECS);
+
+    let channel = load_channel();
+    let cache_path = update_cache_path();
+
+    // A cached result is only honoured while it is both fresh and was fetched for
+    // the channel currently tracked, so switching channels (e.g. beta -> stable)
+    // does not keep surfacing the other channel's version until the interval
+    // elapses.
+    if let Some(path) = &cache_path {
+        if let Some(cache) = read_update_cache(path) {
+            if cache.channel == channel && now.saturating_sub(cache.last_checked) < interval {
+                return available_update_from(&cache.latest_version);
+            }
+        }
+    }
+
+    // Cache is stale, missing, or for a different channel: query the backend on the
+    // tracked channel so a `beta`/`edge` user is notified of newer pre-releases,
+    // but never fail the caller.
+    let latest_version = latest_version_for_channel(channel).ok()?;
+
+    if let Some(path) = &cache_path {
+        let _ = write_update_cache(
+            path,
+            &UpdateCheckCache { last_checked: now, channel, latest_version: latest_version.clone() },
+        );
+    }
+
+    available_update_from(&latest_version)
+}
+
+/// Build an [`AvailableUpdate`] only when `latest` is strictly newer than the
+/// running version.
+fn available_update_from(latest: &str) -> Option<AvailableUpdate> {
+    match bump_is_greater(VERSION, latest) {
+        Ok(true) => Some(AvailableUpdate { version: latest.to_string() }),
+        _ => None,
+    }
+}
+
+/// Fetch the latest version available on `channel` from the GitHub backend.
+///
+/// `stable` uses the backend's own "latest release" lookup; other channels
+/// enumerate all releases and keep the highest in-channel version, so the passive
+/// notification stays consistent with whichever channel `self-update` tracks.
+fn latest_version_for_channel(channel: Channel) -> Result<String, Error> {
+    if channel == Channel::Stable {
+        return fetch_latest_version();
+    }
+
+    let mut list_builder = ReleaseList::configure();
+    list_builder.repo_owner(REPO_OWNER).repo_name(REPO_NAME);
+    let releases = list_builder.build()?.fetch()?;
+
+    releases
+        .into_iter()
+        .filter_map(|release| Version::parse(&release.version).ok().map(|version| (version, release.version)))
+        .filter(|(version, _)| channel.accepts(version))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, tag)| tag)
+        .ok_or_else(|| Error::SelfUpdate(SelfUpdateError::Release("no releases found for the tracked channel".to_string())))
+}
+
+/// Fetch the latest release version from the GitHub backend.
+fn fetch_latest_version() -> Result<String, Error> {
+    let mut status_builder = Update::configure();
+    status_builder
+        .repo_owner(REPO_OWNER)
+        .repo_name(REPO_NAME)
+        .target(TARGET)
+        .bin_name(BIN)
+        .current_version(VERSION);
+
+    let release_update = status_builder.build()?;
+    Ok(release_update.get_latest_release()?.version)
+}
+
+/// Path to the passive update-check cache (`<cache dir>/mago/update-check.json`).
+fn update_cache_path() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("mago").join("update-check.json"))
+}
+
+fn read_update_cache(path: &Path) -> Option<UpdateCheckCache> {
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn write_update_cache(path: &Path, cache: &UpdateCheckCache) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(SelfUpdateError::from)?;
+    }
+    let contents = serde_json::to_string_pretty(cache)
+        .map_err(|error| Error::SelfUpdate(SelfUpdateError::Update(error.to_string())))?;
+    fs::write(path, contents).map_err(SelfUpdateError::from)?;
+    Ok(())
+}
+
+/// Current Unix timestamp in seconds, or `None` if the system clock predates the
+/// epoch.
+fn unix_timestamp() -> Option<u64> {
+    std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).ok().map(|duration| duration.as_secs())
+}
+
+fn perform_update(release_update: Box<dyn ReleaseUpdate>, insecure_skip_verify: bool) -> Result<UpdateStatus, Error> {
     info!("Starting the update process for Mago. Current version: `{}`. Target platform: `{}`.", VERSION, TARGET);
 
     let release = match release_update.target_version() {
@@ -194,6 +497,12 @@ fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus
 
     debug!("Downloaded archive to: {:?}", tmp_archive_path);
 
+    if insecure_skip_verify {
+        warn!("Skipping checksum and signature verification (--insecure-skip-verify).");
+    } else {
+        verify_archive(release_update.as_ref(), &release, target_asset, &tmp_archive_path, tmp_archive_dir.path())?;
+    }
+
     let binary_path = release_update
         .bin_path_in_archive()
         .replace("{{ version }}", &release.version)
@@ -205,6 +514,9 @@ fn perform_update(release_update: Box<dyn ReleaseUpdate>) -> Result<UpdateStatus
 
     let new_executable = tmp_archive_dir.path().join(binary_path);
     debug!("Extracted binary to: {:?}", new_executable);
+
+    backup_current_executable(release_update.bin_install_path())?;
+
     info!("Replacing current executable...");
     self_replace::self_replace(new_executable).map_err(SelfUpdateError::from)?;
     info!("Update complete!");
@@ -233,6 +545,156 @@ fn confirm_prompt(msg: &str) -> Result<(), Error> {
     Ok(())
 }
 
+/// Verify the downloaded archive against the checksum (and optional signature)
+/// manifests published alongside it in the release.
+///
+/// The `<asset>.sha256` manifest is mandatory: the SHA-256 of the archive bytes
+/// must match the hex digest it contains (compared in constant time). When a
+/// [`RELEASE_SIGNING_KEY`] is embedded, a `<asset>.sig` manifest is required and
+/// its detached ed25519 signature over the archive bytes is verified as well;
+/// a missing signature is an error, not a silent skip.
+fn verify_archive(
+    release_update: &dyn ReleaseUpdate,
+    release: &Release,
+    target_asset: &ReleaseAsset,
+    archive_path: &Path,
+    download_dir: &Path,
+) -> Result<(), Error> {
+    let archive_bytes = fs::read(archive_path).map_err(SelfUpdateError::from)?;
+    let digest = Sha256::digest(&archive_bytes);
+    let actual = hex_encode(&digest);
+
+    let checksum_asset = find_manifest_asset(release, target_asset, ".sha256").ok_or_else(|| {
+        Error::SelfUpdate(SelfUpdateError::Release(format!(
+            "No `{}.sha256` checksum manifest found in the release; re-run with --insecure-skip-verify to bypass verification.",
+            target_asset.name
+        )))
+    })?;
+
+    info!("Verifying archive checksum...");
+    let checksum_contents = download_manifest(release_update, checksum_asset, download_dir)?;
+    let expected = parse_sha256_manifest(&checksum_contents).ok_or_else(|| {
+        Error::SelfUpdate(SelfUpdateError::Release("Malformed `.sha256` checksum manifest.".to_string()))
+    })?;
+
+    if !constant_time_eq(actual.as_bytes(), expected.as_bytes()) {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update(format!(
+            "checksum verification failed (expected {expected}, got {actual})"
+        ))));
+    }
+    debug!("Checksum verified: {}", actual);
+
+    if !RELEASE_SIGNING_KEY.is_empty() {
+        match find_manifest_asset(release, target_asset, ".sig") {
+            Some(signature_asset) => {
+                info!("Verifying archive signature...");
+                let signature_bytes = download_manifest(release_update, signature_asset, download_dir)?;
+                verify_signature(&archive_bytes, &signature_bytes)?;
+                debug!("Signature verified with embedded release key.");
+            }
+            None => {
+                return Err(Error::SelfUpdate(SelfUpdateError::Release(format!(
+                    "No `{}.sig` signature manifest found in the release; signature verification cannot be bypassed while a signing key is embedded. Re-run with --insecure-skip-verify only for releases published before signing.",
+                    target_asset.name
+                ))));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Locate a manifest asset (`<asset><suffix>`) accompanying the target archive.
+fn find_manifest_asset<'a>(
+    release: &'a Release,
+    target_asset: &ReleaseAsset,
+    suffix: &str,
+) -> Option<&'a ReleaseAsset> {
+    let expected = format!("{}{}", target_asset.name, suffix);
+    release.assets.iter().find(|asset| asset.name == expected)
+}
+
+/// Download a (small) manifest asset into `download_dir` and return its bytes.
+fn download_manifest(
+    release_update: &dyn ReleaseUpdate,
+    asset: &ReleaseAsset,
+    download_dir: &Path,
+) -> Result<Vec<u8>, Error> {
+    let path = download_dir.join(&asset.name);
+    let mut file = fs::File::create(&path).map_err(SelfUpdateError::from)?;
+
+    let mut download = Download::from_url(&asset.download_url);
+    let mut headers = release_update.api_headers(&release_update.auth_token())?;
+    headers.insert("Accept", "application/octet-stream".parse().unwrap());
+    download.set_headers(headers);
+    download.download_to(&mut file)?;
+
+    fs::read(&path).map_err(|error| Error::SelfUpdate(SelfUpdateError::from(error)))
+}
+
+/// Parse the hex digest out of a `sha256sum`-style manifest, ignoring any
+/// trailing filename column.
+fn parse_sha256_manifest(contents: &[u8]) -> Option<String> {
+    let text = std::str::from_utf8(contents).ok()?;
+    let token = text.split_whitespace().next()?;
+    if token.len() == 64 && token.bytes().all(|b| b.is_ascii_hexdigit()) {
+        Some(token.to_ascii_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Verify a detached ed25519 signature over `message` using [`RELEASE_SIGNING_KEY`].
+fn verify_signature(message: &[u8], signature_bytes: &[u8]) -> Result<(), Error> {
+    let key_bytes = decode_hex(RELEASE_SIGNING_KEY).and_then(|bytes| <[u8; 32]>::try_from(bytes).ok());
+    let key_bytes = key_bytes.ok_or_else(|| {
+        Error::SelfUpdate(SelfUpdateError::Update("signature verification failed: malformed signing key".to_string()))
+    })?;
+    let verifying_key = VerifyingKey::from_bytes(&key_bytes).map_err(|_| {
+        Error::SelfUpdate(SelfUpdateError::Update("signature verification failed: invalid signing key".to_string()))
+    })?;
+
+    let signature = <[u8; 64]>::try_from(signature_bytes).map(|bytes| Signature::from_bytes(&bytes)).map_err(|_| {
+        Error::SelfUpdate(SelfUpdateError::Update("signature verification failed: malformed signature".to_string()))
+    })?;
+
+    verifying_key
+        .verify(message, &signature)
+        .map_err(|_| Error::SelfUpdate(SelfUpdateError::Update("signature verification failed".to_string())))
+}
+
+/// Lowercase hex encoding of a byte slice.
+fn hex_encode(bytes: &[u8]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(out, "{byte:02x}");
+    }
+    out
+}
+
+/// Decode a lowercase/uppercase hex string into bytes, or `None` if malformed.
+fn decode_hex(input: &str) -> Option<Vec<u8>> {
+    if !input.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..input.len()).step_by(2).map(|i| u8::from_str_radix(&input[i..i + 2], 16).ok()).collect()
+}
+
+/// Compare two byte slices in constant time to avoid leaking digest contents
+/// through early-exit timing.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b.iter()) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
 fn get_target_asset_from_release(release: &Release) -> Result<&ReleaseAsset, Error> {
     release
         .assets
@@ -243,6 +705,286 @@ fn get_target_asset_from_release(release: &Release) -> Result<&ReleaseAsset, Err
         })
 }
 
+/// Select the highest release tag on `channel` that is newer than the current
+/// version.
+///
+/// All releases are enumerated from the GitHub backend and their tags parsed as
+/// semver; tags that fail to parse or that fall outside the channel are skipped.
+/// Returns `None` when the channel has no version greater than [`VERSION`], which
+/// keeps a user from being "downgraded" (e.g. a beta user switching to `stable`
+/// whose newest final release is older than their current build).
+fn select_channel_tag(channel: Channel) -> Result<Option<String>, Error> {
+    info!("Enumerating releases for the `{:?}` channel...", channel);
+
+    let mut list_builder = ReleaseList::configure();
+    list_builder.repo_owner(REPO_OWNER).repo_name(REPO_NAME);
+    let releases = list_builder.build()?.fetch()?;
+
+    let current = Version::parse(VERSION).map_err(|error| {
+        Error::SelfUpdate(SelfUpdateError::Update(format!("could not parse current version `{VERSION}`: {error}")))
+    })?;
+
+    let candidates: Vec<(Version, String)> = releases
+        .into_iter()
+        .filter_map(|release| Version::parse(&release.version).ok().map(|version| (version, release.version)))
+        .collect();
+
+    match pick_update_tag(&candidates, channel, &current) {
+        Some(tag) => {
+            info!("New release found on `{:?}` channel! {} --> {}", channel, VERSION, tag);
+            Ok(Some(tag))
+        }
+        None => {
+            debug!("No `{:?}` release newer than current `{}`.", channel, VERSION);
+            Ok(None)
+        }
+    }
+}
+
+/// Pick the highest in-channel tag strictly newer than `current`.
+///
+/// Returns `None` when the channel is empty or its greatest version is not newer
+/// than `current`, which is what keeps a user from being "downgraded" — e.g. a
+/// beta user switching to `stable` whose newest final release is older than the
+/// build they are running.
+fn pick_update_tag(candidates: &[(Version, String)], channel: Channel, current: &Version) -> Option<String> {
+    candidates
+        .iter()
+        .filter(|(version, _)| channel.accepts(version))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .filter(|(version, _)| version > current)
+        .map(|(_, tag)| tag.clone())
+}
+
+/// Persisted self-update preferences, stored next to Mago's other config.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct SelfUpdatePreferences {
+    channel: Channel,
+}
+
+/// Path to the persisted preferences file (`<config dir>/mago/self-update.json`).
+fn preferences_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("mago").join("self-update.json"))
+}
+
+/// Load the persisted channel, defaulting to [`Channel::Stable`] when no
+/// preference has been saved or it cannot be read.
+fn load_channel() -> Channel {
+    let Some(path) = preferences_path() else {
+        return Channel::default();
+    };
+
+    match fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str::<SelfUpdatePreferences>(&contents)
+            .map(|preferences| preferences.channel)
+            .unwrap_or_default(),
+        Err(_) => Channel::default(),
+    }
+}
+
+/// Persist the selected channel so later bare `self-update` runs keep tracking it.
+fn save_channel(channel: Channel) -> Result<(), Error> {
+    let Some(path) = preferences_path() else {
+        return Ok(());
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(SelfUpdateError::from)?;
+    }
+
+    let preferences = SelfUpdatePreferences { channel };
+    let contents = serde_json::to_string_pretty(&preferences)
+        .map_err(|error| Error::SelfUpdate(SelfUpdateError::Update(error.to_string())))?;
+    fs::write(&path, contents).map_err(SelfUpdateError::from)?;
+
+    Ok(())
+}
+
+/// Records the backup of the previously installed executable so it can be
+/// restored with `--rollback`.
+#[derive(Debug, Serialize, Deserialize)]
+struct BackupState {
+    /// The version that was replaced.
+    version: String,
+    /// Absolute path to the backed-up executable.
+    backup_path: PathBuf,
+}
+
+/// Path to the rollback state file (`<config dir>/mago/self-update-backup.json`).
+fn backup_state_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("mago").join("self-update-backup.json"))
+}
+
+/// Copy the currently installed executable to a versioned backup next to it and
+/// record it in the state file, pruning any older backups so only the most recent
+/// one is kept.
+fn backup_current_executable(install_path: &Path) -> Result<(), Error> {
+    let parent = match install_path.parent() {
+        Some(parent) => parent,
+        None => return Ok(()),
+    };
+
+    prune_backups(parent)?;
+
+    let backup_path = parent.join(format!("{BIN}.bak-{VERSION}"));
+    info!("Backing up current executable to {:?}...", backup_path);
+    fs::copy(install_path, &backup_path).map_err(SelfUpdateError::from)?;
+    copy_permissions(install_path, &backup_path);
+
+    if let Some(state_path) = backup_state_path() {
+        if let Some(state_parent) = state_path.parent() {
+            fs::create_dir_all(state_parent).map_err(SelfUpdateError::from)?;
+        }
+        let state = BackupState { version: VERSION.to_string(), backup_path };
+        let contents = serde_json::to_string_pretty(&state)
+            .map_err(|error| Error::SelfUpdate(SelfUpdateError::Update(error.to_string())))?;
+        fs::write(&state_path, contents).map_err(SelfUpdateError::from)?;
+    }
+
+    Ok(())
+}
+
+/// Remove any existing `mago.bak-*` backups in `dir` so a single backup is kept.
+fn prune_backups(dir: &Path) -> Result<(), Error> {
+    let prefix = format!("{BIN}.bak-");
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(()),
+    };
+
+    for entry in entries.flatten() {
+        if entry.file_name().to_string_lossy().starts_with(&prefix) {
+            debug!("Pruning stale backup {:?}", entry.path());
+            let _ = fs::remove_file(entry.path());
+        }
+    }
+
+    Ok(())
+}
+
+/// Restore the previously installed executable recorded in the backup state.
+fn perform_rollback() -> Result<ExitCode, Error> {
+    let state_path = backup_state_path().ok_or_else(|| {
+        Error::SelfUpdate(SelfUpdateError::Update("could not determine the backup state location".to_string()))
+    })?;
+
+    let contents = fs::read_to_string(&state_path).map_err(|_| {
+        Error::SelfUpdate(SelfUpdateError::Update("no rollback backup is available".to_string()))
+    })?;
+    let state: BackupState = serde_json::from_str(&contents)
+        .map_err(|error| Error::SelfUpdate(SelfUpdateError::Update(error.to_string())))?;
+
+    if !state.backup_path.is_file() {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update(format!(
+            "backup for version `{}` is missing at {:?}",
+            state.version, state.backup_path
+        ))));
+    }
+    if !is_executable(&state.backup_path) {
+        return Err(Error::SelfUpdate(SelfUpdateError::Update(format!(
+            "backup for version `{}` is not executable",
+            state.version
+        ))));
+    }
+
+    info!("Rolling back to version `{}` from {:?}...", state.version, state.backup_path);
+    self_replace::self_replace(&state.backup_path).map_err(SelfUpdateError::from)?;
+
+    fs::remove_file(&state_path).map_err(SelfUpdateError::from)?;
+    info!("Rollback complete! Restored version `{}`.", state.version);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Copy the source file's permissions onto the destination (best effort).
+fn copy_permissions(source: &Path, destination: &Path) {
+    if let Ok(metadata) = fs::metadata(source) {
+        let _ = fs::set_permissions(destination, metadata.permissions());
+    }
+}
+
+/// Whether `path` is executable. On Unix this inspects the mode bits; elsewhere it
+/// is treated as executable as long as it is a regular file.
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        fs::metadata(path).map(|metadata| metadata.permissions().mode() & 0o111 != 0).unwrap_or(false)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// The flavour of `brew` binary used to drive a Homebrew upgrade.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum BrewKind {
+    /// A `brew` resolved from `PATH`.
+    PathBrew,
+    /// The Intel macOS Homebrew prefix (`/usr/local`).
+    MacIntel,
+    /// The Apple Silicon macOS Homebrew prefix (`/opt/homebrew`).
+    MacArm,
+}
+
+/// Locate the `brew` binary, preferring the Apple Silicon prefix, then the Intel
+/// prefix, then whatever is on `PATH`.
+fn detect_brew() -> Option<(PathBuf, BrewKind)> {
+    let arm = Path::new("/opt/homebrew/bin/brew");
+    if arm.is_file() {
+        return Some((arm.to_path_buf(), BrewKind::MacArm));
+    }
+
+    let intel = Path::new("/usr/local/bin/brew");
+    if intel.is_file() {
+        return Some((intel.to_path_buf(), BrewKind::MacIntel));
+    }
+
+    // Fall back to a `brew` discoverable on `PATH`; `None` when none is found, so
+    // `run_homebrew_upgrade` can report the missing binary rather than spawning a
+    // command that cannot exist.
+    brew_on_path().map(|brew| (brew, BrewKind::PathBrew))
+}
+
+/// Locate a `brew` executable on `PATH`, if any.
+fn brew_on_path() -> Option<PathBuf> {
+    let path = env::var_os("PATH")?;
+    env::split_paths(&path).map(|dir| dir.join("brew")).find(|candidate| is_executable(candidate))
+}
+
+/// Delegate the upgrade to Homebrew, offering to run `brew upgrade mago` and
+/// propagating its exit status.
+fn run_homebrew_upgrade(no_confirm: bool) -> Result<ExitCode, Error> {
+    let (brew, kind) = detect_brew().ok_or_else(|| {
+        Error::SelfUpdate(SelfUpdateError::Update("could not locate a `brew` binary to upgrade with".to_string()))
+    })?;
+    debug!("Using Homebrew binary {:?} ({:?})", brew, kind);
+
+    info!("This looks like a Homebrew installation; Mago will upgrade it with `brew upgrade mago`.");
+    if !no_confirm {
+        confirm_prompt("Run `brew upgrade mago` now? [Y/n] ")?;
+    }
+
+    let status = Command::new(&brew).arg("upgrade").arg("mago").status().map_err(SelfUpdateError::from)?;
+
+    match status.code() {
+        Some(0) => {
+            info!("Homebrew upgrade complete!");
+            Ok(ExitCode::SUCCESS)
+        }
+        Some(code) => {
+            warn!("`brew upgrade mago` exited with status {}", code);
+            Ok(ExitCode::from(code as u8))
+        }
+        None => {
+            warn!("`brew upgrade mago` was terminated by a signal");
+            Ok(ExitCode::FAILURE)
+        }
+    }
+}
+
 fn is_homebrew_install() -> bool {
     let exe_path = match env::current_exe() {
         Ok(path) => path,
@@ -316,4 +1058,93 @@ mod tests {
         let path = PathBuf::from("/usr/local/bin/mago");
         assert!(!detect_homebrew_install(&[path], &[]));
     }
+
+    #[test]
+    fn parses_bare_sha256_digest() {
+        let digest = "a".repeat(64);
+        assert_eq!(parse_sha256_manifest(digest.as_bytes()), Some(digest.clone()));
+    }
+
+    #[test]
+    fn parses_sha256sum_style_manifest() {
+        let contents = "ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789ABCDEF0123456789  mago.tar.gz\n";
+        assert_eq!(
+            parse_sha256_manifest(contents.as_bytes()),
+            Some("abcdef0123456789abcdef0123456789abcdef0123456789abcdef0123456789".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_manifest_of_wrong_length() {
+        assert_eq!(parse_sha256_manifest(b"deadbeef  mago.tar.gz"), None);
+    }
+
+    #[test]
+    fn rejects_manifest_with_non_hex() {
+        let token = "z".repeat(64);
+        assert_eq!(parse_sha256_manifest(token.as_bytes()), None);
+    }
+
+    #[test]
+    fn decodes_round_trip_hex() {
+        assert_eq!(decode_hex("00ff10"), Some(vec![0x00, 0xff, 0x10]));
+        assert_eq!(hex_encode(&[0x00, 0xff, 0x10]), "00ff10");
+    }
+
+    #[test]
+    fn rejects_odd_or_invalid_hex() {
+        assert_eq!(decode_hex("abc"), None);
+        assert_eq!(decode_hex("gg"), None);
+    }
+
+    #[test]
+    fn constant_time_eq_matches_only_equal_slices() {
+        assert!(constant_time_eq(b"abc", b"abc"));
+        assert!(!constant_time_eq(b"abc", b"abd"));
+        assert!(!constant_time_eq(b"abc", b"ab"));
+    }
+
+    fn v(s: &str) -> Version {
+        Version::parse(s).unwrap()
+    }
+
+    #[test]
+    fn channel_accepts_partition_by_pre_release() {
+        assert!(Channel::Stable.accepts(&v("1.2.3")));
+        assert!(!Channel::Stable.accepts(&v("1.2.3-beta.1")));
+
+        assert!(Channel::Beta.accepts(&v("1.2.3-beta.1")));
+        assert!(Channel::Beta.accepts(&v("1.2.3-rc.2")));
+        assert!(!Channel::Beta.accepts(&v("1.2.3")));
+        assert!(!Channel::Beta.accepts(&v("1.2.3-alpha.1")));
+
+        assert!(Channel::Edge.accepts(&v("1.2.3")));
+        assert!(Channel::Edge.accepts(&v("1.2.3-alpha.1")));
+    }
+
+    #[test]
+    fn pick_update_tag_selects_highest_in_channel() {
+        let candidates = vec![
+            (v("1.0.0"), "1.0.0".to_string()),
+            (v("1.1.0"), "1.1.0".to_string()),
+            (v("1.2.0-beta.1"), "1.2.0-beta.1".to_string()),
+        ];
+        assert_eq!(pick_update_tag(&candidates, Channel::Stable, &v("1.0.0")), Some("1.1.0".to_string()));
+        assert_eq!(pick_update_tag(&candidates, Channel::Beta, &v("1.0.0")), Some("1.2.0-beta.1".to_string()));
+        assert_eq!(pick_update_tag(&candidates, Channel::Edge, &v("1.0.0")), Some("1.2.0-beta.1".to_string()));
+    }
+
+    #[test]
+    fn pick_update_tag_never_downgrades() {
+        // A beta user on 1.2.0-beta.1 switching to stable must not be offered an
+        // older final release.
+        let candidates = vec![(v("1.1.0"), "1.1.0".to_string()), (v("1.2.0-beta.1"), "1.2.0-beta.1".to_string())];
+        assert_eq!(pick_update_tag(&candidates, Channel::Stable, &v("1.2.0-beta.1")), None);
+    }
+
+    #[test]
+    fn pick_update_tag_empty_channel_is_none() {
+        let candidates = vec![(v("1.0.0"), "1.0.0".to_string())];
+        assert_eq!(pick_update_tag(&candidates, Channel::Beta, &v("0.9.0")), None);
+    }
 }